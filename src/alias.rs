@@ -0,0 +1,65 @@
+use crate::Config;
+use anyhow::Result;
+
+/// Expand a user-defined alias at the front of the argument vector, if any.
+///
+/// Aliases live in the `[alias]` section of [`Config`] and map a shorthand
+/// subcommand to its expansion, e.g.
+///
+/// ```toml
+/// [alias]
+/// demo = "demo start --rpc-url http://localhost:9002"
+/// ws = ["workspace", "--rpc-url", "http://localhost:9002"]
+/// ```
+///
+/// Both a whitespace-split string form and a list form are supported. The
+/// lookup runs on startup, after `Config` is loaded but before argument
+/// parsing: if `args[1]` (the first argument after the binary name) matches
+/// a defined alias, its expansion is spliced in place of that token.
+///
+/// Unknown first arguments (including real subcommands) are returned
+/// unchanged, so aliases never shadow built-in commands that aren't aliased.
+pub fn expand_aliases(config: &Config, mut args: Vec<String>) -> Result<Vec<String>> {
+    // args[0] is the binary name; the candidate subcommand is args[1].
+    let Some(candidate) = args.get(1).cloned() else {
+        return Ok(args);
+    };
+
+    let Some(expansion) = lookup_alias(config, &candidate)? else {
+        return Ok(args);
+    };
+
+    // Splice the expansion in place of the matched token, keeping any
+    // trailing arguments the user passed after it.
+    let tail = args.split_off(2);
+    args.truncate(1);
+    args.extend(expansion);
+    args.extend(tail);
+    Ok(args)
+}
+
+/// Resolve a single alias to its expanded tokens, accepting either a string
+/// (whitespace-split) or a list value.
+fn lookup_alias(config: &Config, name: &str) -> Result<Option<Vec<String>>> {
+    let table = match config.get_table("alias") {
+        Ok(table) => table,
+        // No `[alias]` section at all.
+        Err(_) => return Ok(None),
+    };
+
+    let Some(value) = table.get(name) else {
+        return Ok(None);
+    };
+
+    // List form takes precedence; fall back to the whitespace-split string.
+    if let Ok(list) = value.clone().into_array() {
+        let tokens = list
+            .into_iter()
+            .map(|v| v.into_string())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(tokens))
+    } else {
+        let raw = value.clone().into_string()?;
+        Ok(Some(raw.split_whitespace().map(String::from).collect()))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::package;
+use crate::registry::{self, LoginArgs, PublishArgs};
+use crate::workspace::{self, NewArgs};
+use crate::{get_config_dir, get_keypair_from_name, Config};
+use anyhow::Result;
+use arch_program::pubkey::Pubkey;
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// Subcommands that dispatch into the registry/workspace/packaging
+/// subsystems. `main` parses these as the CLI's subcommands and hands the
+/// parsed variant to [`dispatch`], so the subsystems are reachable from the
+/// CLI rather than being library-only entry points.
+#[derive(Subcommand, Debug)]
+pub enum ArchCommand {
+    /// Persist a registry API token next to keys.json in the config dir.
+    Login {
+        /// API token issued by the registry.
+        api_token: String,
+    },
+    /// Publish a built program artifact to the registry by name+version.
+    Publish {
+        /// Path to the package (directory containing its Cargo.toml).
+        #[arg(default_value = ".")]
+        package: PathBuf,
+    },
+    /// Deploy a packaged program from the local content-addressed index,
+    /// verifying its checksum before deploying under the named keypair.
+    DeployFromIndex {
+        /// Program name as recorded in the index.
+        name: String,
+        /// Program version as recorded in the index.
+        version: String,
+        /// Key name (in keys.json) to deploy the program under.
+        key_name: String,
+        /// RPC endpoint override.
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Scaffold a new workspace member and append it to arch.toml.
+    New {
+        /// Name of the new member project.
+        name: String,
+    },
+    /// Run the whole workspace: create accounts, deploy, and build the
+    /// frontend for every member declared in arch.toml.
+    Workspace {
+        /// RPC endpoint override.
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+/// Execute a dispatched [`ArchCommand`].
+pub async fn dispatch(command: ArchCommand, config: &Config) -> Result<()> {
+    match command {
+        ArchCommand::Login { api_token } => registry::login(&LoginArgs { api_token }),
+        ArchCommand::Publish { package } => {
+            registry::publish(&PublishArgs { package }, config).await
+        }
+        ArchCommand::DeployFromIndex {
+            name,
+            version,
+            key_name,
+            rpc_url,
+        } => {
+            let keys_file = get_config_dir()?.join("keys.json");
+            let keypair = get_keypair_from_name(&key_name, &keys_file)?;
+            let pubkey = Pubkey::from_slice(&keypair.public_key().serialize()[1..33]);
+            package::deploy_program_from_index(
+                &name,
+                &version,
+                config,
+                Some((keypair, pubkey)),
+                rpc_url,
+            )
+            .await
+        }
+        ArchCommand::New { name } => {
+            let base_dir = PathBuf::from(config.get_string("project.directory")?);
+            workspace::new_project(&base_dir, &NewArgs { name })
+        }
+        ArchCommand::Workspace { rpc_url } => {
+            let base_dir = PathBuf::from(config.get_string("project.directory")?);
+            let network = config
+                .get_string("bitcoin.network")
+                .unwrap_or_else(|_| "regtest".to_string());
+            let rpc_url = rpc_url
+                .or_else(|| config.get_string("leader_rpc_endpoint").ok())
+                .unwrap_or_else(|| common::constants::NODE1_ADDRESS.to_string());
+            workspace::run_workspace(&base_dir, &rpc_url, &network, config).await
+        }
+    }
+}
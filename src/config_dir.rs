@@ -0,0 +1,46 @@
+use anyhow::{anyhow, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Resolve the CLI config directory following platform conventions.
+///
+/// Uses the XDG base directory on Linux, `~/Library/Application Support` on
+/// macOS, and the Roaming `AppData` location on Windows (all via the `dirs`
+/// crate), rather than a single hardcoded path. The directory is created if
+/// missing, and an existing `keys.json` from the legacy `~/.arch-cli`
+/// location is migrated in on first run so users don't lose keys when they
+/// upgrade or switch machines.
+pub fn get_config_dir() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow!("Could not determine platform config directory"))?
+        .join("arch-cli");
+
+    fs::create_dir_all(&config_dir)?;
+
+    migrate_legacy_keys(&config_dir)?;
+
+    Ok(config_dir)
+}
+
+/// Move a pre-existing `keys.json` from the old hardcoded `~/.arch-cli`
+/// directory into `config_dir` if the new location does not already have
+/// one. A no-op once migrated.
+fn migrate_legacy_keys(config_dir: &Path) -> Result<()> {
+    let new_keys = config_dir.join("keys.json");
+    if new_keys.exists() {
+        return Ok(());
+    }
+
+    let Some(home) = dirs::home_dir() else {
+        return Ok(());
+    };
+    let legacy_keys = home.join(".arch-cli").join("keys.json");
+    if legacy_keys.exists() {
+        fs::rename(&legacy_keys, &new_keys).or_else(|_| {
+            // Fall back to copy if the rename crosses a filesystem boundary.
+            fs::copy(&legacy_keys, &new_keys).map(|_| ())
+        })?;
+    }
+
+    Ok(())
+}
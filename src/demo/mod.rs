@@ -0,0 +1,5 @@
+mod build;
+mod setup;
+
+pub use build::build_frontend;
+pub use setup::setup_demo_environment;
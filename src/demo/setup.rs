@@ -132,13 +132,35 @@ pub async fn setup_demo_environment(
     let program_pubkey_bytes = Pubkey::from_slice(&program_keypair.public_key().serialize()[1..33]);
 
     // Note: Using shared program directory for deployment
-    deploy_program_from_path(
-        &base_dir.join("program"), // Using shared program directory
-        config,
-        Some((program_keypair.clone(), program_pubkey_bytes)),
-        Some(rpc_url.clone()),
-    )
-    .await?;
+    let program_dir = base_dir.join("program");
+    if config.get_bool("verifiable_build").unwrap_or(false) {
+        // Verifiable path: build inside the pinned-toolchain container,
+        // package the resulting ELF with its checksum, then verify and
+        // deploy that byte-identical artifact.
+        let elf = crate::verifiable_build::build_in_container(&program_dir, &base_dir, config)?;
+        let manifest = crate::package::PackageManifest {
+            name: graffiti_key_name.clone(),
+            version: "0.1.0".to_string(),
+            target: "sbf-solana-solana".to_string(),
+        };
+        crate::package::package_program(&elf, &manifest)?;
+        crate::package::deploy_program_from_index(
+            &manifest.name,
+            &manifest.version,
+            config,
+            Some((program_keypair.clone(), program_pubkey_bytes)),
+            Some(rpc_url.clone()),
+        )
+        .await?;
+    } else {
+        deploy_program_from_path(
+            &program_dir, // Using shared program directory
+            config,
+            Some((program_keypair.clone(), program_pubkey_bytes)),
+            Some(rpc_url.clone()),
+        )
+        .await?;
+    }
 
     make_program_executable(
         &program_keypair,
@@ -162,7 +184,7 @@ pub async fn setup_demo_environment(
         create_account(
             &CreateAccountArgs {
                 name: "graffiti_wall_state".to_string(),
-                program_id: Some(hex::encode(program_pubkey_bytes.serialize())),
+                program_id: Some(program_pubkey_bytes.to_string()),
                 rpc_url: Some(rpc_url.clone()),
             },
             config,
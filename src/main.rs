@@ -0,0 +1,52 @@
+//! `arch-cli` entry point: load the layered `Config`, then parse and
+//! dispatch subcommands.
+//!
+//! The registry, packaging, verifiable-build, and workspace subsystems are
+//! registered here as modules. The registry, packaging, and workspace ones
+//! are reached through [`commands::dispatch`]; verifiable builds run from the
+//! demo setup when `verifiable_build` is set in config.
+
+mod alias;
+mod commands;
+mod config_dir;
+mod demo;
+mod package;
+mod registry;
+mod verifiable_build;
+mod workspace;
+
+pub use config_dir::get_config_dir;
+
+use anyhow::Result;
+use clap::Parser;
+use commands::ArchCommand;
+use config::Config;
+
+#[derive(Parser, Debug)]
+#[command(name = "arch-cli", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: ArchCommand,
+}
+
+/// Load the layered configuration from the platform config dir, overlaying
+/// environment variables with the `ARCH` prefix and `__` as the separator.
+fn load_config() -> Result<Config> {
+    let config_file = get_config_dir()?.join("config.toml");
+    let config = Config::builder()
+        .add_source(config::File::from(config_file).required(false))
+        .add_source(config::Environment::with_prefix("ARCH").separator("__"))
+        .build()?;
+    Ok(config)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let config = load_config()?;
+
+    // Expand a user-defined alias before clap sees the arguments.
+    let args = alias::expand_aliases(&config, std::env::args().collect())?;
+
+    let cli = Cli::parse_from(args);
+    commands::dispatch(cli.command, &config).await
+}
@@ -0,0 +1,178 @@
+use crate::{deploy_program_from_elf, get_config_dir, Config};
+use anyhow::{anyhow, Context, Result};
+use arch_program::pubkey::Pubkey;
+use colored::*;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Metadata packed alongside the ELF inside the archive, so an unpacked
+/// program carries enough context to be identified without the index.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PackageManifest {
+    pub name: String,
+    pub version: String,
+    pub target: String,
+}
+
+/// A sparse index entry describing one packaged program.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub name: String,
+    pub version: String,
+    /// Lowercase hex SHA-256 of the gzip'd tar archive.
+    pub checksum: String,
+}
+
+/// Directory under the config dir that holds archives and index entries.
+fn index_dir() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("index"))
+}
+
+fn archive_path(dir: &Path, name: &str, version: &str) -> PathBuf {
+    dir.join(format!("{}-{}.tar.gz", name, version))
+}
+
+fn entry_path(dir: &Path, name: &str, version: &str) -> PathBuf {
+    dir.join(format!("{}-{}.json", name, version))
+}
+
+/// Compute the lowercase hex SHA-256 of a byte slice.
+fn checksum(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Package a compiled ELF into a gzip'd tar archive with its metadata
+/// manifest, then record a sparse index entry under the config dir.
+///
+/// Returns the index entry (including the archive checksum) so callers can
+/// surface the content address of the redeployable artifact.
+pub fn package_program(elf: &Path, manifest: &PackageManifest) -> Result<IndexEntry> {
+    let elf_bytes =
+        fs::read(elf).with_context(|| format!("Failed to read ELF at {:?}", elf))?;
+
+    // tar { manifest.json, program.so } -> gzip.
+    let mut archive_bytes = Vec::new();
+    {
+        let encoder = GzEncoder::new(&mut archive_bytes, Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+
+        let manifest_json = serde_json::to_vec_pretty(manifest)?;
+        append_bytes(&mut builder, "manifest.json", &manifest_json)?;
+        append_bytes(&mut builder, "program.so", &elf_bytes)?;
+
+        builder.into_inner()?.finish()?;
+    }
+
+    let entry = IndexEntry {
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        checksum: checksum(&archive_bytes),
+    };
+
+    let dir = index_dir()?;
+    fs::create_dir_all(&dir)?;
+    fs::write(archive_path(&dir, &entry.name, &entry.version), &archive_bytes)?;
+    fs::write(
+        entry_path(&dir, &entry.name, &entry.version),
+        serde_json::to_vec_pretty(&entry)?,
+    )?;
+
+    println!(
+        "  {} Packaged {} v{} ({})",
+        "✓".bold().green(),
+        entry.name,
+        entry.version,
+        entry.checksum
+    );
+    Ok(entry)
+}
+
+/// Append an in-memory blob to a tar archive under `name`.
+fn append_bytes<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    bytes: &[u8],
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, name, bytes)?;
+    Ok(())
+}
+
+/// Deploy a program from the local content-addressed index.
+///
+/// Looks up the `{ name, version, checksum }` entry, re-reads the archive,
+/// verifies the stored SHA-256 before unpacking, validates the ELF, and
+/// deploys it — giving reproducible, tamper-evident redeploys instead of
+/// rebuilding from source on every run.
+pub async fn deploy_program_from_index(
+    name: &str,
+    version: &str,
+    config: &Config,
+    signer: Option<(secp256k1::Keypair, Pubkey)>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let dir = index_dir()?;
+
+    let entry_bytes = fs::read(entry_path(&dir, name, version)).with_context(|| {
+        format!("No index entry for {} v{}; package it first", name, version)
+    })?;
+    let entry: IndexEntry = serde_json::from_slice(&entry_bytes)?;
+
+    let archive_bytes = fs::read(archive_path(&dir, name, version))
+        .with_context(|| format!("Missing archive for {} v{}", name, version))?;
+
+    let actual = checksum(&archive_bytes);
+    if actual != entry.checksum {
+        return Err(anyhow!(
+            "Checksum mismatch for {} v{}: expected {}, found {}",
+            name,
+            version,
+            entry.checksum,
+            actual
+        ));
+    }
+
+    // Unpack into a fresh directory under the index dir.
+    let unpack_dir = dir.join(format!("{}-{}", name, version));
+    if unpack_dir.exists() {
+        fs::remove_dir_all(&unpack_dir)?;
+    }
+    fs::create_dir_all(&unpack_dir)?;
+    tar::Archive::new(GzDecoder::new(&archive_bytes[..]))
+        .unpack(&unpack_dir)
+        .context("Failed to unpack verified archive")?;
+
+    let elf = unpack_dir.join("program.so");
+    validate_elf(&elf)?;
+
+    println!(
+        "  {} Verified {} v{} ({}); deploying",
+        "✓".bold().green(),
+        name,
+        version,
+        entry.checksum
+    );
+
+    // Deploy the already-verified ELF directly, without re-running the
+    // from-source build path.
+    deploy_program_from_elf(&elf, config, signer, rpc_url).await
+}
+
+/// Cheap sanity check that the unpacked file is an ELF before deploy.
+fn validate_elf(elf: &Path) -> Result<()> {
+    let bytes = fs::read(elf).with_context(|| format!("Missing ELF at {:?}", elf))?;
+    if bytes.len() < 4 || &bytes[..4] != b"\x7fELF" {
+        return Err(anyhow!("{:?} is not a valid ELF", elf));
+    }
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use crate::{get_config_dir, Config};
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `[registry]` section of the CLI [`Config`].
+#[derive(Clone, Debug)]
+pub struct RegistryConfig {
+    /// Base URL of the program index the CLI publishes to and fetches from.
+    pub index_url: String,
+    /// Directory the published artifacts are written to before upload.
+    pub output_dir: PathBuf,
+}
+
+impl RegistryConfig {
+    /// Resolve the `[registry]` section from config, falling back to the
+    /// index URL and config-dir `registry/` output dir when unset.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let index_url = config
+            .get_string("registry.index_url")
+            .unwrap_or_else(|_| "https://registry.arch.network".to_string());
+        let output_dir = match config.get_string("registry.output_dir") {
+            Ok(dir) => PathBuf::from(dir),
+            Err(_) => get_config_dir()?.join("registry"),
+        };
+        Ok(Self {
+            index_url,
+            output_dir,
+        })
+    }
+}
+
+/// Arguments for `arch-cli login <api-token>`.
+#[derive(Clone, Debug)]
+pub struct LoginArgs {
+    pub api_token: String,
+}
+
+/// Arguments for `arch-cli publish <package>`.
+#[derive(Clone, Debug)]
+pub struct PublishArgs {
+    /// Path to the package (directory containing its `Cargo.toml`).
+    pub package: PathBuf,
+}
+
+/// Minimal view of the `[package]` table of a project's `Cargo.toml`.
+#[derive(Debug, Deserialize)]
+struct CargoManifest {
+    package: CargoPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    name: String,
+    version: String,
+}
+
+/// A record of a program published to the registry, written to the registry
+/// output dir after a successful upload.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PublishedProgram {
+    pub name: String,
+    pub version: String,
+    pub index_url: String,
+}
+
+/// Persist the API token next to `keys.json` in the config dir.
+///
+/// The token is written with `0600` permissions on Unix so it is not
+/// world-readable, matching how the keypair store is treated.
+pub fn login(args: &LoginArgs) -> Result<()> {
+    let token_file = get_config_dir()?.join("credentials.json");
+
+    let credentials = serde_json::json!({ "api_token": args.api_token });
+    fs::write(&token_file, serde_json::to_vec_pretty(&credentials)?)
+        .with_context(|| format!("Failed to write credentials to {:?}", token_file))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&token_file, fs::Permissions::from_mode(0o600))?;
+    }
+
+    println!(
+        "  {} Saved registry credentials to {:?}",
+        "✓".bold().green(),
+        token_file
+    );
+    Ok(())
+}
+
+/// Read the persisted API token, erroring with a hint to `login` first.
+fn read_api_token() -> Result<String> {
+    let token_file = get_config_dir()?.join("credentials.json");
+    let contents = fs::read_to_string(&token_file)
+        .map_err(|_| anyhow!("Not logged in. Run `arch-cli login <api-token>` first."))?;
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    value
+        .get("api_token")
+        .and_then(|t| t.as_str())
+        .map(|t| t.to_string())
+        .ok_or_else(|| anyhow!("Malformed credentials file at {:?}", token_file))
+}
+
+/// Read `name`/`version` from the package's `Cargo.toml`.
+fn read_manifest(package: &Path) -> Result<CargoPackage> {
+    let manifest_path = package.join("Cargo.toml");
+    let contents = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read manifest at {:?}", manifest_path))?;
+    let manifest: CargoManifest = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse manifest at {:?}", manifest_path))?;
+    Ok(manifest.package)
+}
+
+/// Upload the built program artifact for `package` and record the release.
+///
+/// Reads the project's Cargo manifest for the name/version, uploads the
+/// compiled artifact from the registry output dir to the index, and writes
+/// a local record so the release is discoverable without another round trip.
+pub async fn publish(args: &PublishArgs, config: &Config) -> Result<()> {
+    let registry = RegistryConfig::from_config(config)?;
+    let token = read_api_token()?;
+    let package = read_manifest(&args.package)?;
+
+    println!(
+        "{} {} v{}",
+        "Publishing".bold().green(),
+        package.name,
+        package.version
+    );
+
+    let artifact = registry
+        .output_dir
+        .join(format!("{}-{}.so", package.name, package.version));
+    let bytes = fs::read(&artifact)
+        .with_context(|| format!("Built artifact not found at {:?}", artifact))?;
+
+    let url = format!(
+        "{}/api/v1/programs/{}/{}",
+        registry.index_url.trim_end_matches('/'),
+        package.name,
+        package.version
+    );
+    let response = reqwest::Client::new()
+        .put(&url)
+        .bearer_auth(&token)
+        .body(bytes)
+        .send()
+        .await
+        .context("Failed to upload program artifact")?;
+
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "Registry rejected publish ({}): {}",
+            response.status(),
+            response.text().await.unwrap_or_default()
+        ));
+    }
+
+    let record = PublishedProgram {
+        name: package.name.clone(),
+        version: package.version.clone(),
+        index_url: registry.index_url.clone(),
+    };
+    let record_file = registry
+        .output_dir
+        .join(format!("{}-{}.json", package.name, package.version));
+    fs::create_dir_all(&registry.output_dir)?;
+    fs::write(&record_file, serde_json::to_vec_pretty(&record)?)?;
+
+    println!(
+        "  {} Published {} v{} to {}",
+        "✓".bold().green(),
+        package.name,
+        package.version,
+        registry.index_url
+    );
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::Config;
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Dockerfile template used for verifiable builds.
+///
+/// `{{ image }}` is replaced with the pinned base image and `{{ pkg }}` with
+/// the package path relative to the build context. The whole workspace is
+/// copied in so path dependencies resolve; the build runs as a non-root
+/// `builder` user and leaves the compiled program in `/out` so it can be
+/// copied back to the host byte-for-byte.
+const DOCKERFILE_TEMPLATE: &str = r#"FROM {{ image }}
+RUN useradd --create-home --user-group builder \
+    && mkdir -p /out \
+    && chown builder:builder /out
+USER builder
+WORKDIR /build
+COPY --chown=builder:builder . .
+WORKDIR /build/{{ pkg }}
+RUN cargo build-sbf --sbf-out-dir /out
+"#;
+
+/// Verifiable-build settings threaded from the root of [`Config`].
+///
+/// `arch_version` records the exact CLI/toolchain used, so a build can be
+/// reproduced later against the same pinned image.
+#[derive(Clone, Debug)]
+pub struct VerifiableBuildConfig {
+    pub arch_version: String,
+}
+
+impl VerifiableBuildConfig {
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let arch_version = config
+            .get_string("arch_version")
+            .context("`arch_version` must be set in config for verifiable builds")?;
+        Ok(Self { arch_version })
+    }
+
+    /// The pinned base image derived from the recorded toolchain version.
+    fn base_image(&self) -> String {
+        format!("ghcr.io/arch-network/arch-build:{}", self.arch_version)
+    }
+}
+
+/// Render the Dockerfile template for a package path.
+fn render_dockerfile(image: &str, pkg: &str) -> String {
+    DOCKERFILE_TEMPLATE
+        .replace("{{ image }}", image)
+        .replace("{{ pkg }}", pkg)
+}
+
+/// Build `package` inside a container from the pinned toolchain image and
+/// copy the resulting ELF out of `/out` onto the host.
+///
+/// Returns the path to the extracted program on the host. Using a pinned
+/// image makes the output byte-identical across machines, so others can
+/// independently verify a deployed program.
+pub fn build_in_container(
+    package: &Path,
+    workspace_root: &Path,
+    config: &Config,
+) -> Result<PathBuf> {
+    let settings = VerifiableBuildConfig::from_config(config)?;
+    let image = settings.base_image();
+
+    let pkg_name = package
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| anyhow!("Invalid package path {:?}", package))?;
+
+    // Build from the workspace root so the `../common|program|bip322` path
+    // dependencies resolve inside the container.
+    let pkg_rel = package
+        .strip_prefix(workspace_root)
+        .map_err(|_| anyhow!("{:?} is not under workspace root {:?}", package, workspace_root))?
+        .to_str()
+        .ok_or_else(|| anyhow!("Non-UTF-8 package path {:?}", package))?;
+
+    // Keep the generated Dockerfile and extracted artifact out of the
+    // working tree by staging them under a scratch dir in TMPDIR.
+    let scratch = std::env::temp_dir().join(format!("arch-verifiable-{}", pkg_name));
+    let out_dir = scratch.join("out");
+    fs::create_dir_all(&out_dir)?;
+    let dockerfile = scratch.join("Dockerfile");
+    fs::write(&dockerfile, render_dockerfile(&image, pkg_rel))?;
+
+    let tag = format!("arch-verifiable/{}", pkg_name);
+    println!(
+        "  {} Building {} in container ({})",
+        "ℹ".bold().blue(),
+        pkg_name,
+        image
+    );
+
+    run(Command::new("docker").args([
+        "build",
+        "-f",
+        dockerfile.to_str().unwrap(),
+        "-t",
+        &tag,
+        workspace_root.to_str().unwrap(),
+    ]))?;
+
+    // Copy the ELF out of /out via an ephemeral container.
+    let cid = capture(Command::new("docker").args(["create", &tag]))?;
+    let cid = cid.trim();
+    run(Command::new("docker").args([
+        "cp",
+        &format!("{}:/out/.", cid),
+        out_dir.to_str().unwrap(),
+    ]))?;
+    run(Command::new("docker").args(["rm", cid]))?;
+
+    let elf = fs::read_dir(&out_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.extension().map(|x| x == "so").unwrap_or(false))
+        .ok_or_else(|| anyhow!("No .so produced in {:?}", out_dir))?;
+
+    println!(
+        "  {} Verifiable build produced {:?} (toolchain {})",
+        "✓".bold().green(),
+        elf,
+        settings.arch_version
+    );
+    Ok(elf)
+}
+
+/// Run a command, mapping a non-zero exit into an error.
+fn run(cmd: &mut Command) -> Result<()> {
+    let status = cmd
+        .status()
+        .with_context(|| format!("Failed to spawn {:?}", cmd))?;
+    if !status.success() {
+        return Err(anyhow!("Command {:?} exited with {}", cmd, status));
+    }
+    Ok(())
+}
+
+/// Run a command and capture its stdout.
+fn capture(cmd: &mut Command) -> Result<String> {
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to spawn {:?}", cmd))?;
+    if !output.status.success() {
+        return Err(anyhow!("Command {:?} exited with {}", cmd, output.status));
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
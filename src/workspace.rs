@@ -0,0 +1,210 @@
+use crate::{
+    build_frontend, create_account, deploy_program_from_path, get_config_dir,
+    get_keypair_from_name, get_pubkey_from_name, key_name_exists, make_program_executable, Config,
+    CreateAccountArgs,
+};
+use anyhow::{anyhow, Context, Result};
+use arch_program::pubkey::Pubkey;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `arch.toml` workspace manifest listing the member projects the CLI
+/// operates on, replacing the hardcoded `projects/demo` bootstrap.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ArchManifest {
+    #[serde(default)]
+    pub members: Vec<Member>,
+}
+
+/// A single workspace member: its program directory, frontend path, and the
+/// account names the CLI creates/deploys for it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Member {
+    pub name: String,
+    pub program_dir: PathBuf,
+    pub frontend_path: PathBuf,
+    #[serde(default)]
+    pub accounts: Vec<String>,
+}
+
+impl ArchManifest {
+    fn path(base_dir: &Path) -> PathBuf {
+        base_dir.join("arch.toml")
+    }
+
+    /// Load the manifest from `base_dir`, erroring if it is missing so the
+    /// caller can prompt the user to `arch-cli new` their first member.
+    pub fn load(base_dir: &Path) -> Result<Self> {
+        let path = Self::path(base_dir);
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("No workspace manifest at {:?}", path))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {:?}", path))
+    }
+
+    fn save(&self, base_dir: &Path) -> Result<()> {
+        let path = Self::path(base_dir);
+        fs::write(&path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {:?}", path))
+    }
+}
+
+/// Iterate the workspace members, creating accounts, deploying each program,
+/// and running `build_frontend` per member.
+pub async fn run_workspace(
+    base_dir: &Path,
+    rpc_url: &str,
+    network: &str,
+    config: &Config,
+) -> Result<()> {
+    let manifest = ArchManifest::load(base_dir)?;
+    let keys_file = get_config_dir()?.join("keys.json");
+
+    for member in &manifest.members {
+        println!("{} {}", "Processing member:".bold().green(), member.name);
+
+        // Ensure every declared account exists.
+        for account in &member.accounts {
+            if !key_name_exists(&keys_file, account)? {
+                println!("  {} Creating account {}", "ℹ".bold().blue(), account);
+                create_account(
+                    &CreateAccountArgs {
+                        name: account.clone(),
+                        program_id: None,
+                        rpc_url: Some(rpc_url.to_string()),
+                    },
+                    config,
+                )
+                .await?;
+            }
+        }
+
+        // The first declared account owns the program itself.
+        let program_key = member
+            .accounts
+            .first()
+            .ok_or_else(|| anyhow!("Member {} declares no accounts", member.name))?;
+        let program_keypair = get_keypair_from_name(program_key, &keys_file)?;
+        let program_pubkey_bytes =
+            Pubkey::from_slice(&program_keypair.public_key().serialize()[1..33]);
+
+        deploy_program_from_path(
+            &base_dir.join(&member.program_dir),
+            config,
+            Some((program_keypair.clone(), program_pubkey_bytes)),
+            Some(rpc_url.to_string()),
+        )
+        .await?;
+
+        make_program_executable(
+            &program_keypair,
+            &program_pubkey_bytes,
+            Some(rpc_url.to_string()),
+        )
+        .await?;
+
+        let program_pubkey = get_pubkey_from_name(program_key, &keys_file)?;
+        let wall_pubkey = member
+            .accounts
+            .get(1)
+            .map(|a| get_pubkey_from_name(a, &keys_file))
+            .transpose()?
+            .unwrap_or_default();
+
+        build_frontend(
+            &base_dir.join(&member.frontend_path),
+            Some(rpc_url),
+            &program_pubkey,
+            &wall_pubkey,
+            network,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Arguments for `arch-cli new <name>`.
+#[derive(Clone, Debug)]
+pub struct NewArgs {
+    pub name: String,
+}
+
+/// Scaffold a new workspace member and append it to `arch.toml`.
+pub fn new_project(base_dir: &Path, args: &NewArgs) -> Result<()> {
+    let mut manifest = ArchManifest::load(base_dir).unwrap_or_default();
+
+    if manifest.members.iter().any(|m| m.name == args.name) {
+        return Err(anyhow!("Member {} already exists", args.name));
+    }
+
+    let project_dir = PathBuf::from("projects").join(&args.name);
+    let member = Member {
+        name: args.name.clone(),
+        program_dir: project_dir.join("program"),
+        // build_frontend appends `app/frontend/.env`, so the member root is
+        // the frontend path it expects.
+        frontend_path: project_dir.clone(),
+        accounts: vec![args.name.clone()],
+    };
+
+    scaffold_member(base_dir, &member)?;
+
+    manifest.members.push(member);
+    manifest.save(base_dir)?;
+
+    println!(
+        "  {} Created workspace member {} at projects/{}",
+        "✓".bold().green(),
+        args.name,
+        args.name
+    );
+    Ok(())
+}
+
+/// Write the program and frontend files for a new member so it is buildable
+/// and deployable as soon as it is created.
+fn scaffold_member(base_dir: &Path, member: &Member) -> Result<()> {
+    let program_dir = base_dir.join(&member.program_dir);
+    fs::create_dir_all(program_dir.join("src"))?;
+
+    let cargo_toml = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = {{ path = "../../../common" }}
+program = {{ path = "../../../program" }}
+bip322 = {{ path = "../../../bip322" }}
+"#,
+        name = member.name
+    );
+    fs::write(program_dir.join("Cargo.toml"), cargo_toml)?;
+
+    let lib_rs = r#"use program::entrypoint;
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &program::pubkey::Pubkey,
+    _accounts: &[program::account::AccountInfo],
+    _instruction_data: &[u8],
+) -> program::entrypoint::ProgramResult {
+    Ok(())
+}
+"#;
+    fs::write(program_dir.join("src/lib.rs"), lib_rs)?;
+
+    // Frontend .env with the placeholders build_frontend fills in.
+    let frontend_dir = base_dir.join(&member.frontend_path).join("app/frontend");
+    fs::create_dir_all(&frontend_dir)?;
+    let env = "VITE_PROGRAM_PUBKEY=\n\
+        VITE_WALL_ACCOUNT_PUBKEY=\n\
+        VITE_NETWORK=\n\
+        VITE_RPC_URL=\n";
+    fs::write(frontend_dir.join(".env"), env)?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[repr(C)]
 #[derive(
@@ -12,13 +12,15 @@ use serde::{Deserialize, Serialize};
     Ord,
     Default,
     Copy,
-    Serialize,
-    Deserialize,
     BorshSerialize,
     BorshDeserialize,
 )]
 pub struct Pubkey(pub [u8; 32]);
 
+/// Bitcoin base58 alphabet, shared with the rest of the Arch/Solana ecosystem.
+const BASE58_ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
 impl Pubkey {
     pub fn serialize(&self) -> [u8; 32] {
         self.0
@@ -72,12 +74,134 @@ impl std::fmt::LowerHex for Pubkey {
 }
 
 use core::fmt;
+use core::fmt::Write as _;
+
+/// Error returned when a string cannot be parsed into a [`Pubkey`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParsePubkeyError {
+    /// The string contained a character outside the base58 alphabet.
+    Invalid,
+    /// The decoded byte string was not exactly 32 bytes long.
+    WrongSize,
+}
 
-/// TODO:
-///  Change this in future according to the correct base implementation
+impl fmt::Display for ParsePubkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePubkeyError::Invalid => write!(f, "invalid base58 string"),
+            ParsePubkeyError::WrongSize => write!(f, "decoded pubkey was not 32 bytes"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePubkeyError {}
+
+/// Encode the 32 bytes as a canonical base58 string, treating the array as a
+/// big-endian integer and emitting one leading `1` per leading zero byte.
 impl fmt::Display for Pubkey {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.0)
+        let mut digits: Vec<u8> = Vec::with_capacity(44);
+        for &byte in self.0.iter() {
+            let mut carry = byte as usize;
+            for digit in digits.iter_mut() {
+                carry += (*digit as usize) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        // Preserve leading zero bytes as leading '1's.
+        for &byte in self.0.iter() {
+            if byte != 0 {
+                break;
+            }
+            digits.push(0);
+        }
+
+        for &digit in digits.iter().rev() {
+            f.write_char(BASE58_ALPHABET[digit as usize] as char)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Pubkey {
+    type Err = ParsePubkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A 32-byte value encodes to at most 44 base58 characters; reject
+        // anything longer up front so untrusted input can't trigger the
+        // quadratic decode loop below.
+        if s.len() > 44 {
+            return Err(ParsePubkeyError::WrongSize);
+        }
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(32);
+        for ch in s.bytes() {
+            let value = BASE58_ALPHABET
+                .iter()
+                .position(|&c| c == ch)
+                .ok_or(ParsePubkeyError::Invalid)?;
+            let mut carry = value;
+            for byte in bytes.iter_mut() {
+                carry += (*byte as usize) * 58;
+                *byte = (carry & 0xff) as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push((carry & 0xff) as u8);
+                carry >>= 8;
+            }
+        }
+
+        // Restore the leading zero bytes encoded as leading '1's.
+        for ch in s.bytes() {
+            if ch != b'1' {
+                break;
+            }
+            bytes.push(0);
+        }
+
+        bytes.reverse();
+        if bytes.len() != 32 {
+            return Err(ParsePubkeyError::WrongSize);
+        }
+
+        let mut tmp = [0u8; 32];
+        tmp.copy_from_slice(&bytes);
+        Ok(Pubkey(tmp))
+    }
+}
+
+impl Serialize for Pubkey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Pubkey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        } else {
+            let bytes = <[u8; 32]>::deserialize(deserializer)?;
+            Ok(Pubkey(bytes))
+        }
     }
 }
 
@@ -103,6 +227,7 @@ impl From<[u8; 32]> for Pubkey {
 mod tests {
     use crate::pubkey::Pubkey;
     use proptest::prelude::*;
+    use std::str::FromStr;
 
     proptest! {
         #[test]
@@ -112,5 +237,24 @@ mod tests {
             let deserialized = Pubkey::from_slice(&serialized);
             assert_eq!(pubkey, deserialized);
         }
+
+        #[test]
+        fn fuzz_base58_round_trip(data in any::<[u8; 32]>()) {
+            let pubkey = Pubkey::from(data);
+            let encoded = pubkey.to_string();
+            let decoded = Pubkey::from_str(&encoded).unwrap();
+            assert_eq!(pubkey, decoded);
+        }
+    }
+
+    #[test]
+    fn system_program_base58_round_trip() {
+        let pubkey = Pubkey::system_program();
+        assert_eq!(Pubkey::from_str(&pubkey.to_string()).unwrap(), pubkey);
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert!(Pubkey::from_str("1").is_err());
     }
 }